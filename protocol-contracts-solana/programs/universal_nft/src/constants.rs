@@ -0,0 +1,3 @@
+/// Wormhole-style chain id for Solana itself, used to tag natively-minted
+/// NFTs when they're locked (rather than burned) on an outbound transfer.
+pub const SOLANA_CHAIN_ID: u16 = 1;
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Unauthorized gateway")]
+    UnauthorizedGateway,
+    #[msg("Invalid payload")]
+    InvalidPayload,
+    #[msg("Replay attack detected")]
+    ReplayAttack,
+    #[msg("Replay PDA mismatch")]
+    ReplayPdaMismatch,
+    #[msg("Token account must hold exactly 1 token")]
+    InvalidTokenAmount,
+    #[msg("Custody PDA mismatch")]
+    CustodyPdaMismatch,
+    #[msg("No custody record exists for this mint to release")]
+    CustodyRecordNotFound,
+    #[msg("NFT origin mint does not match the provided mint")]
+    NftOriginMintMismatch,
+    #[msg("Gateway nonce overflow")]
+    NonceOverflow,
+    #[msg("Malformed VAA")]
+    InvalidVaa,
+    #[msg("Guardian set PDA does not match the VAA's guardian_set_index")]
+    GuardianSetMismatch,
+    #[msg("Signature references a guardian index outside the guardian set")]
+    InvalidGuardianIndex,
+    #[msg("Guardian signature failed to recover")]
+    InvalidGuardianSignature,
+    #[msg("Not enough valid guardian signatures to reach quorum")]
+    QuorumNotMet,
+    #[msg("Emitter is not on the gateway's allow-list")]
+    UnauthorizedEmitter,
+    #[msg("VAA sequence has already been processed")]
+    SequenceReplay,
+    #[msg("app_payload exceeds the maximum allowed size")]
+    PayloadTooLarge,
+    #[msg("Collection config does not match the provided collection mint")]
+    CollectionMintMismatch,
+    #[msg("Only the marker's original payer or the gateway authority may prune it")]
+    UnauthorizedPrune,
+    #[msg("Replay marker has not yet passed the gateway's retention window")]
+    ReplayMarkerNotYetPrunable,
+    #[msg("Payload nonce is below the origin's watermark and cannot be replayed")]
+    NonceBelowWatermark,
+    #[msg("Sequence marker has not yet passed the gateway's retention window")]
+    SequenceMarkerNotYetPrunable,
+    #[msg("VAA sequence is below the emitter's watermark and cannot be replayed")]
+    SequenceBelowWatermark,
+    #[msg("Only the gateway authority may perform this action")]
+    UnauthorizedGatewayAuthority,
+    #[msg("Too many allowed emitters for the gateway config's fixed capacity")]
+    TooManyAllowedEmitters,
+    #[msg("Too many guardians for a guardian set's fixed capacity")]
+    TooManyGuardians,
+}
@@ -1,78 +1,309 @@
-﻿use anchor_lang::prelude::*;
+use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
-use crate::state::nft_origin::{NftOrigin, CrossChainNftPayload};
+use crate::constants::SOLANA_CHAIN_ID;
+use crate::errors::ErrorCode;
+use crate::state::collection::CollectionConfig;
+use crate::state::custody::CustodyRecord;
 use crate::state::gateway::GatewayConfig;
+use crate::state::guardian::GuardianSet;
+use crate::state::nft_origin::{CrossChainNftPayload, NftOrigin, MAX_APP_PAYLOAD_LEN};
+use crate::state::nonce_watermark::NonceWatermark;
 use crate::state::replay::ReplayMarker;
-use crate::utils::{derive_nft_origin_pda, derive_replay_marker_pda};
+use crate::state::sequence_marker::SequenceMarker;
+use crate::state::sequence_watermark::SequenceWatermark;
+use crate::utils::{
+    derive_collection_authority_pda, derive_custody_authority_pda, derive_custody_record_pda,
+    derive_guardian_set_pda, derive_master_edition_pda, derive_metadata_pda,
+    derive_mint_authority_pda, derive_nft_origin_pda, derive_nonce_watermark_pda,
+    derive_replay_marker_pda, derive_sequence_marker_pda, derive_sequence_watermark_pda,
+    COLLECTION_AUTHORITY_SEED, CUSTODY_AUTHORITY_SEED, MINT_AUTHORITY_SEED,
+};
+use crate::vaa::Vaa;
 
+/// Accounts shared by both the wrap and release paths: guardian verification
+/// and the replay-protection mechanisms (VAA sequence and payload nonce)
+/// apply to every inbound message regardless of what it does with the NFT.
 #[derive(Accounts)]
-pub struct HandleIncoming<'info> {
+pub struct HandleIncomingCommon<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     pub recipient: SystemAccount<'info>,
+    /// CHECK: gateway program config PDA
+    pub gateway_config: UncheckedAccount<'info>,
+    /// Guardian set the VAA's signatures are checked against.
+    pub guardian_set: Account<'info, GuardianSet>,
+    /// CHECK: replay marker account, keyed on the payload's (token_id, nonce)
+    #[account(mut)]
+    pub replay_marker: UncheckedAccount<'info>,
+    /// CHECK: per-origin-token nonce watermark left by pruned replay markers;
+    /// may be uninitialized if none of this token's markers have been pruned yet
+    #[account(mut)]
+    pub nonce_watermark: UncheckedAccount<'info>,
+    /// CHECK: replay marker account, keyed on the VAA's (emitter_chain, emitter_address, sequence)
+    #[account(mut)]
+    pub sequence_marker: UncheckedAccount<'info>,
+    /// CHECK: per-emitter sequence watermark left by pruned sequence markers;
+    /// may be uninitialized if none of this emitter's markers have been pruned yet
+    #[account(mut)]
+    pub sequence_watermark: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for minting a fresh wrapped copy of an NFT that originated on
+/// another chain. Doesn't carry any custody accounts, since this path never
+/// touches the custody token account a native NFT would have been locked in.
+#[derive(Accounts)]
+pub struct HandleIncomingWrap<'info> {
+    pub common: HandleIncomingCommon<'info>,
+    /// CHECK: fresh mint created via CPI below for this newly bridged NFT.
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
     #[account(
-        init,
-        payer = payer,
-        mint::decimals = 0,
-        mint::authority = payer,
-        mint::freeze_authority = payer,
+        init_if_needed,
+        payer = common.payer,
+        associated_token::mint = mint,
+        associated_token::authority = common.recipient
     )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    /// CHECK: PDA holding mint/freeze authority; also signs the metadata and
+    /// master-edition CPIs below as their update/mint authority.
+    #[account(seeds = [MINT_AUTHORITY_SEED], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    /// CHECK: Metaplex metadata PDA for `mint`, initialized via CPI below.
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+    /// CHECK: Metaplex master edition PDA for `mint`, initialized via CPI below.
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+    /// The Solana collection this NFT is verified into.
+    pub collection_config: Account<'info, CollectionConfig>,
+    pub collection_mint: Account<'info, Mint>,
+    /// CHECK: Metaplex metadata PDA for `collection_mint`
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+    /// CHECK: Metaplex master edition PDA for `collection_mint`
+    pub collection_master_edition: UncheckedAccount<'info>,
+    /// CHECK: PDA that is update authority over `collection_mint` and signs the
+    /// `verify_sized_collection_item` CPI below.
+    #[account(seeds = [COLLECTION_AUTHORITY_SEED], bump)]
+    pub collection_authority: UncheckedAccount<'info>,
+    /// CHECK: origin record for this wrapped NFT, created here on first mint.
+    #[account(mut)]
+    pub nft_origin: UncheckedAccount<'info>,
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Accounts for releasing a natively-minted NFT back out of custody. Doesn't
+/// carry any collection/metadata accounts, since this path never mints or
+/// verifies a new collection member.
+#[derive(Accounts)]
+pub struct HandleIncomingRelease<'info> {
+    pub common: HandleIncomingCommon<'info>,
+    /// The already-initialized native mint being released from custody.
+    #[account(mut)]
     pub mint: Account<'info, Mint>,
     #[account(
-        init,
-        payer = payer,
+        init_if_needed,
+        payer = common.payer,
         associated_token::mint = mint,
-        associated_token::authority = recipient
+        associated_token::authority = common.recipient
     )]
     pub recipient_token_account: Account<'info, TokenAccount>,
-    /// CHECK: gateway program config PDA
-    pub gateway_config: UncheckedAccount<'info>,
-    /// CHECK: replay marker account
-    #[account(mut)]
-    pub replay_marker: UncheckedAccount<'info>,
+    /// CHECK: program-owned authority over custody token accounts; signs the
+    /// transfer back out of `custody_token_account`.
+    #[account(seeds = [CUSTODY_AUTHORITY_SEED], bump)]
+    pub custody_authority: UncheckedAccount<'info>,
+    /// Holds the natively-minted NFT locked by `TransferOut`'s custody path;
+    /// this path only ever transfers out of it, so it's never `init_if_needed`.
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = custody_authority,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+    /// CHECK: marks a natively-originated mint as locked; cleared here once
+    /// the token is returned to `recipient`
+    #[account(mut, seeds = [CustodyRecord::SEED, mint.key().as_ref()], bump)]
+    pub custody_record: UncheckedAccount<'info>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<HandleIncoming>, payload: Vec<u8>) -> Result<()> {
-    let clock = Clock::get()?;
-
-    // Load gateway config from PDA and verify signer program id from payload origin (out-of-band)
+/// Parses and verifies the guardian-signed VAA, enforces the emitter
+/// allow-list, the sequence/nonce replay protections, and the
+/// guardian-verified recipient pin, then writes the sequence and replay
+/// markers proving this VAA has now been consumed. Shared by both the wrap
+/// and release paths so neither one pays for accounts only the other needs.
+fn verify_and_consume_vaa(
+    accs: &HandleIncomingCommon,
+    clock: &Clock,
+    vaa: Vec<u8>,
+) -> Result<CrossChainNftPayload> {
+    // Load gateway config from PDA
     let (cfg_pda, _bump) = Pubkey::find_program_address(&[GatewayConfig::SEED], &crate::ID);
-    require_keys_eq!(ctx.accounts.gateway_config.key(), cfg_pda, ErrorCode::UnauthorizedGateway);
-    let data = ctx.accounts.gateway_config.try_borrow_data()?;
-    let cfg = GatewayConfig::try_from_slice(&data[8..]).map_err(|_| ErrorCode::UnauthorizedGateway)?;
+    require_keys_eq!(
+        accs.gateway_config.key(),
+        cfg_pda,
+        ErrorCode::UnauthorizedGateway
+    );
+    let data = accs.gateway_config.try_borrow_data()?;
+    let cfg =
+        GatewayConfig::try_from_slice(&data[8..]).map_err(|_| ErrorCode::UnauthorizedGateway)?;
+    drop(data);
+
+    // Parse and verify the guardian-signed VAA carrying the transfer payload.
+    let vaa = Vaa::parse(&vaa)?;
+    let (guardian_set_pda, _guardian_set_bump) =
+        derive_guardian_set_pda(vaa.header.guardian_set_index);
+    require_keys_eq!(
+        accs.guardian_set.key(),
+        guardian_set_pda,
+        ErrorCode::GuardianSetMismatch
+    );
+    vaa.verify_signatures(&accs.guardian_set)?;
+
+    // Enforce the emitter allow-list so only trusted source-chain contracts can mint.
+    let emitter_allowed = cfg.allowed_emitters.iter().any(|e| {
+        e.chain_id == vaa.body.emitter_chain && e.emitter_address == vaa.body.emitter_address
+    });
+    require!(emitter_allowed, ErrorCode::UnauthorizedEmitter);
+
+    // Sequence-based replay protection: a VAA sequence must never be processed twice,
+    // independent of the (token_id, nonce) marker below.
+    let (sequence_watermark_pda, _sequence_watermark_bump) =
+        derive_sequence_watermark_pda(vaa.body.emitter_chain, &vaa.body.emitter_address);
+    require_keys_eq!(
+        accs.sequence_watermark.key(),
+        sequence_watermark_pda,
+        ErrorCode::InvalidPayload
+    );
+    if !accs.sequence_watermark.data_is_empty() {
+        let data = accs.sequence_watermark.try_borrow_data()?;
+        let watermark = SequenceWatermark::try_deserialize(&mut &data[..])?;
+        drop(data);
+        require!(
+            vaa.body.sequence >= watermark.min_valid_sequence,
+            ErrorCode::SequenceBelowWatermark
+        );
+    }
+
+    let (sequence_marker_pda, sequence_bump) = derive_sequence_marker_pda(
+        vaa.body.emitter_chain,
+        &vaa.body.emitter_address,
+        vaa.body.sequence,
+    );
+    require_keys_eq!(
+        accs.sequence_marker.key(),
+        sequence_marker_pda,
+        ErrorCode::ReplayPdaMismatch
+    );
+    if !accs.sequence_marker.data_is_empty() {
+        return Err(ErrorCode::SequenceReplay.into());
+    }
+    let sequence_space = 8 + SequenceMarker::LEN;
+    let sequence_lamports = Rent::get()?.minimum_balance(sequence_space);
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::create_account(
+            &accs.payer.key(),
+            &sequence_marker_pda,
+            sequence_lamports,
+            sequence_space as u64,
+            &crate::ID,
+        ),
+        &[
+            accs.payer.to_account_info(),
+            accs.sequence_marker.to_account_info(),
+            accs.system_program.to_account_info(),
+        ],
+        &[&[
+            SequenceMarker::SEED,
+            &vaa.body.emitter_chain.to_le_bytes(),
+            &vaa.body.emitter_address,
+            &vaa.body.sequence.to_le_bytes(),
+            &[sequence_bump],
+        ]],
+    )?;
+    let sequence_marker = SequenceMarker {
+        emitter_chain: vaa.body.emitter_chain,
+        emitter_address: vaa.body.emitter_address,
+        sequence: vaa.body.sequence,
+        created_at: clock.unix_timestamp,
+        payer: accs.payer.key(),
+        bump: sequence_bump,
+    };
+    let mut sequence_data = accs.sequence_marker.try_borrow_mut_data()?;
+    sequence_marker.try_serialize(&mut &mut sequence_data[..])?;
+    drop(sequence_data);
 
-    // Deserialize payload
-    let p: CrossChainNftPayload = CrossChainNftPayload::try_from_slice(&payload)
+    // Deserialize the application payload carried inside the VAA body
+    let p: CrossChainNftPayload = CrossChainNftPayload::try_from_slice(&vaa.body.payload)
         .map_err(|_| ErrorCode::InvalidPayload)?;
+    if let Some(app_payload) = &p.app_payload {
+        require!(
+            app_payload.len() <= MAX_APP_PAYLOAD_LEN,
+            ErrorCode::PayloadTooLarge
+        );
+    }
+    // A relayed VAA is public once signed; pin the mint/release to the
+    // guardian-verified recipient so whoever relays it can't redirect it.
+    require_keys_eq!(accs.recipient.key(), p.recipient, ErrorCode::InvalidPayload);
+
+    // Reject nonces a pruned replay marker already proved were consumed.
+    let (nonce_watermark_pda, _nonce_watermark_bump) = derive_nonce_watermark_pda(&p.token_id);
+    require_keys_eq!(
+        accs.nonce_watermark.key(),
+        nonce_watermark_pda,
+        ErrorCode::InvalidPayload
+    );
+    if !accs.nonce_watermark.data_is_empty() {
+        let data = accs.nonce_watermark.try_borrow_data()?;
+        let watermark = NonceWatermark::try_deserialize(&mut &data[..])?;
+        drop(data);
+        require!(
+            p.nonce >= watermark.min_valid_nonce,
+            ErrorCode::NonceBelowWatermark
+        );
+    }
 
     // Replay protection: derive and ensure empty
     let (replay_pda, bump) = derive_replay_marker_pda(&p.token_id, p.nonce);
-    require_keys_eq!(ctx.accounts.replay_marker.key(), replay_pda, ErrorCode::ReplayPdaMismatch);
-    if !ctx.accounts.replay_marker.data_is_empty() {
+    require_keys_eq!(
+        accs.replay_marker.key(),
+        replay_pda,
+        ErrorCode::ReplayPdaMismatch
+    );
+    if !accs.replay_marker.data_is_empty() {
         return Err(ErrorCode::ReplayAttack.into());
     }
     let space = 8 + ReplayMarker::LEN;
     let lamports = Rent::get()?.minimum_balance(space);
     anchor_lang::solana_program::program::invoke_signed(
         &anchor_lang::solana_program::system_instruction::create_account(
-            &ctx.accounts.payer.key(),
+            &accs.payer.key(),
             &replay_pda,
             lamports,
             space as u64,
             &crate::ID,
         ),
         &[
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.replay_marker.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
+            accs.payer.to_account_info(),
+            accs.replay_marker.to_account_info(),
+            accs.system_program.to_account_info(),
         ],
-        &[&[ReplayMarker::SEED, &p.token_id, &p.nonce.to_le_bytes(), &[bump]]],
+        &[&[
+            ReplayMarker::SEED,
+            &p.token_id,
+            &p.nonce.to_le_bytes(),
+            &[bump],
+        ]],
     )?;
 
     // Write replay marker
@@ -80,64 +311,245 @@ pub fn handler(ctx: Context<HandleIncoming>, payload: Vec<u8>) -> Result<()> {
         token_id: p.token_id,
         nonce: p.nonce,
         created_at: clock.unix_timestamp,
+        payer: accs.payer.key(),
         bump,
     };
-    let mut data = ctx.accounts.replay_marker.try_borrow_mut_data()?;
+    let mut data = accs.replay_marker.try_borrow_mut_data()?;
     marker.try_serialize(&mut &mut data[..])?;
+    drop(data);
 
-    // Mint 1 token to recipient
-    anchor_spl::token::mint_to(
+    Ok(p)
+}
+
+pub fn wrap_handler(ctx: Context<HandleIncomingWrap>, vaa: Vec<u8>) -> Result<()> {
+    let clock = Clock::get()?;
+    let p = verify_and_consume_vaa(&ctx.accounts.common, &clock, vaa)?;
+    require!(
+        p.origin_chain_id != SOLANA_CHAIN_ID,
+        ErrorCode::InvalidPayload
+    );
+
+    // The payload's collection must match the Solana collection this inbound
+    // message is verified against.
+    require!(
+        ctx.accounts.collection_config.source_chain == p.origin_chain_id
+            && ctx.accounts.collection_config.source_collection_id == p.source_collection_id,
+        ErrorCode::CollectionMintMismatch
+    );
+    require_keys_eq!(
+        ctx.accounts.collection_config.collection_mint,
+        ctx.accounts.collection_mint.key(),
+        ErrorCode::CollectionMintMismatch
+    );
+
+    // Create a fresh mint for this bridged NFT.
+    let (mint_authority_pda, mint_authority_bump) = derive_mint_authority_pda();
+    require_keys_eq!(
+        ctx.accounts.mint_authority.key(),
+        mint_authority_pda,
+        ErrorCode::InvalidPayload
+    );
+    let mint_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[mint_authority_bump]];
+
+    let mint_space = Mint::LEN;
+    let mint_lamports = Rent::get()?.minimum_balance(mint_space);
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::create_account(
+            &ctx.accounts.common.payer.key(),
+            &ctx.accounts.mint.key(),
+            mint_lamports,
+            mint_space as u64,
+            &ctx.accounts.token_program.key(),
+        ),
+        &[
+            ctx.accounts.common.payer.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+        ],
+    )?;
+    anchor_spl::token::initialize_mint(
         CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::InitializeMint {
+                mint: ctx.accounts.mint.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+        ),
+        0,
+        &mint_authority_pda,
+        Some(&mint_authority_pda),
+    )?;
+
+    // Mint 1 token to recipient, signed by the program's mint-authority PDA.
+    anchor_spl::token::mint_to(
+        CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             anchor_spl::token::MintTo {
                 mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.recipient_token_account.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
             },
+            &[mint_authority_seeds],
         ),
         1,
     )?;
 
-    // Create or update nft_origin PDA
+    // Attach Metaplex metadata so the mint is recognized as a real NFT rather
+    // than an anonymous SPL token.
+    let (metadata_pda, _metadata_bump) = derive_metadata_pda(&ctx.accounts.mint.key());
+    require_keys_eq!(
+        ctx.accounts.metadata.key(),
+        metadata_pda,
+        ErrorCode::InvalidPayload
+    );
+    let (master_edition_pda, _master_edition_bump) =
+        derive_master_edition_pda(&ctx.accounts.mint.key());
+    require_keys_eq!(
+        ctx.accounts.master_edition.key(),
+        master_edition_pda,
+        ErrorCode::InvalidPayload
+    );
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &mpl_token_metadata::instruction::create_metadata_accounts_v3(
+            mpl_token_metadata::ID,
+            metadata_pda,
+            ctx.accounts.mint.key(),
+            mint_authority_pda,
+            ctx.accounts.common.payer.key(),
+            mint_authority_pda,
+            p.name.clone(),
+            p.symbol.clone(),
+            p.metadata_uri.clone(),
+            None,
+            0,
+            true,
+            true,
+            Some(mpl_token_metadata::state::Collection {
+                verified: false,
+                key: ctx.accounts.collection_mint.key(),
+            }),
+            None,
+            None,
+        ),
+        &[
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.common.payer.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.common.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        &[mint_authority_seeds],
+    )?;
+
+    // Master edition with max_supply = 0 makes this a true 1/1 non-fungible.
+    anchor_lang::solana_program::program::invoke_signed(
+        &mpl_token_metadata::instruction::create_master_edition_v3(
+            mpl_token_metadata::ID,
+            master_edition_pda,
+            ctx.accounts.mint.key(),
+            mint_authority_pda,
+            mint_authority_pda,
+            metadata_pda,
+            ctx.accounts.common.payer.key(),
+            Some(0),
+        ),
+        &[
+            ctx.accounts.master_edition.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.common.payer.to_account_info(),
+            ctx.accounts.common.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        &[mint_authority_seeds],
+    )?;
+
+    // Verify this NFT as a member of its bridged collection, signed by the
+    // program's collection-authority PDA (the collection's update authority).
+    let (collection_authority_pda, collection_authority_bump) = derive_collection_authority_pda();
+    require_keys_eq!(
+        ctx.accounts.collection_authority.key(),
+        collection_authority_pda,
+        ErrorCode::InvalidPayload
+    );
+    let collection_authority_seeds: &[&[u8]] =
+        &[COLLECTION_AUTHORITY_SEED, &[collection_authority_bump]];
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &mpl_token_metadata::instruction::verify_sized_collection_item(
+            mpl_token_metadata::ID,
+            metadata_pda,
+            collection_authority_pda,
+            ctx.accounts.common.payer.key(),
+            ctx.accounts.collection_mint.key(),
+            ctx.accounts.collection_metadata.key(),
+            ctx.accounts.collection_master_edition.key(),
+            None,
+        ),
+        &[
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.collection_authority.to_account_info(),
+            ctx.accounts.common.payer.to_account_info(),
+            ctx.accounts.collection_mint.to_account_info(),
+            ctx.accounts.collection_metadata.to_account_info(),
+            ctx.accounts.collection_master_edition.to_account_info(),
+        ],
+        &[collection_authority_seeds],
+    )?;
+
+    // Create or update the nft_origin PDA recording where this wrapped NFT came from.
     let (nft_origin_pda, nft_origin_bump) = derive_nft_origin_pda(&p.token_id);
-    
+    require_keys_eq!(
+        ctx.accounts.nft_origin.key(),
+        nft_origin_pda,
+        ErrorCode::InvalidPayload
+    );
+
     let nft_origin = NftOrigin {
         origin_chain: p.origin_chain_id,
         origin_token_id: p.token_id,
         origin_mint: p.origin_mint,
         metadata_uri: p.metadata_uri,
+        wrapped_mint: ctx.accounts.mint.key(),
+        source_collection_id: p.source_collection_id,
         created_at: clock.unix_timestamp,
         bump: nft_origin_bump,
     };
 
-    // Create the nft_origin account if it doesn't exist
-    if ctx.accounts.payer.key() != &nft_origin_pda {
+    // Create the nft_origin account if it doesn't exist yet.
+    if ctx.accounts.nft_origin.data_is_empty() {
+        let space = 8 + NftOrigin::LEN;
+        let lamports = Rent::get()?.minimum_balance(space);
         anchor_lang::solana_program::program::invoke_signed(
             &anchor_lang::solana_program::system_instruction::create_account(
-                &ctx.accounts.payer.key(),
+                &ctx.accounts.common.payer.key(),
                 &nft_origin_pda,
-                Rent::get()?.minimum_balance(NftOrigin::LEN),
-                NftOrigin::LEN as u64,
+                lamports,
+                space as u64,
                 &crate::ID,
             ),
             &[
-                ctx.accounts.payer.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.common.payer.to_account_info(),
+                ctx.accounts.nft_origin.to_account_info(),
+                ctx.accounts.common.system_program.to_account_info(),
             ],
             &[&[NftOrigin::SEED, &p.token_id, &[nft_origin_bump]]],
         )?;
     }
 
-    // Initialize the nft_origin account with data
-    let mut nft_origin_account = anchor_lang::solana_program::account_info::AccountInfo::try_from(&nft_origin_pda)?;
-    let mut data = nft_origin_account.try_borrow_mut_data()?;
+    let mut data = ctx.accounts.nft_origin.try_borrow_mut_data()?;
     nft_origin.try_serialize(&mut &mut data[..])?;
+    drop(data);
 
-    // Emit cross-chain mint event
     emit!(CrossChainMintEvent {
         token_id: p.token_id,
         origin_chain: p.origin_chain_id,
         recipient: p.recipient,
+        sender: p.sender,
         nonce: p.nonce,
         timestamp: clock.unix_timestamp,
     });
@@ -150,23 +562,96 @@ pub fn handler(ctx: Context<HandleIncoming>, payload: Vec<u8>) -> Result<()> {
     Ok(())
 }
 
+pub fn release_handler(ctx: Context<HandleIncomingRelease>, vaa: Vec<u8>) -> Result<()> {
+    let clock = Clock::get()?;
+    let p = verify_and_consume_vaa(&ctx.accounts.common, &clock, vaa)?;
+    require!(
+        p.origin_chain_id == SOLANA_CHAIN_ID,
+        ErrorCode::InvalidPayload
+    );
+
+    // This NFT was minted natively on Solana and locked by `TransferOut`'s
+    // custody path when it left the chain. Return the custodied token to
+    // `recipient` instead of minting a new wrapped copy.
+    let native_mint = Pubkey::new_from_array(p.origin_mint);
+    require_keys_eq!(
+        ctx.accounts.mint.key(),
+        native_mint,
+        ErrorCode::NftOriginMintMismatch
+    );
+
+    let (custody_authority_pda, custody_authority_bump) = derive_custody_authority_pda();
+    require_keys_eq!(
+        ctx.accounts.custody_authority.key(),
+        custody_authority_pda,
+        ErrorCode::InvalidPayload
+    );
+    let (custody_record_pda, _custody_record_bump) = derive_custody_record_pda(&native_mint);
+    require_keys_eq!(
+        ctx.accounts.custody_record.key(),
+        custody_record_pda,
+        ErrorCode::CustodyPdaMismatch
+    );
+    require!(
+        !ctx.accounts.custody_record.data_is_empty(),
+        ErrorCode::CustodyRecordNotFound
+    );
+    let record_data = ctx.accounts.custody_record.try_borrow_data()?;
+    let record = CustodyRecord::try_deserialize(&mut &record_data[..])?;
+    drop(record_data);
+    require_keys_eq!(record.mint, native_mint, ErrorCode::NftOriginMintMismatch);
+
+    let custody_authority_seeds: &[&[u8]] = &[CUSTODY_AUTHORITY_SEED, &[custody_authority_bump]];
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: ctx.accounts.custody_token_account.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.custody_authority.to_account_info(),
+            },
+            &[custody_authority_seeds],
+        ),
+        1,
+    )?;
+
+    // Zero the record so a later outbound transfer re-locks this mint from scratch.
+    let mut record_data = ctx.accounts.custody_record.try_borrow_mut_data()?;
+    record_data.fill(0);
+    drop(record_data);
+
+    emit!(CrossChainReleaseEvent {
+        token_id: p.token_id,
+        mint: native_mint,
+        recipient: p.recipient,
+        sender: p.sender,
+        nonce: p.nonce,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Released custodied Universal NFT from cross-chain transfer");
+    msg!("Mint: {}", native_mint);
+    msg!("Recipient: {}", ctx.accounts.common.recipient.key());
+
+    Ok(())
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct CrossChainMintEvent {
     pub token_id: [u8; 32],
     pub origin_chain: u16,
     pub recipient: Pubkey,
+    pub sender: [u8; 32],
     pub nonce: u64,
     pub timestamp: i64,
 }
 
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Unauthorized gateway")]
-    UnauthorizedGateway,
-    #[msg("Invalid payload")]
-    InvalidPayload,
-    #[msg("Replay attack detected")]
-    ReplayAttack,
-    #[msg("Replay PDA mismatch")]
-    ReplayPdaMismatch,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CrossChainReleaseEvent {
+    pub token_id: [u8; 32],
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub sender: [u8; 32],
+    pub nonce: u64,
+    pub timestamp: i64,
 }
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::state::collection::CollectionConfig;
+use crate::state::gateway::GatewayConfig;
+use crate::utils::{
+    derive_collection_authority_pda, derive_master_edition_pda, derive_metadata_pda,
+    COLLECTION_AUTHORITY_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(source_chain: u16, source_collection_id: [u8; 32])]
+pub struct InitCollection<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [GatewayConfig::SEED],
+        bump = gateway_config.bump,
+        constraint = gateway_config.authority == payer.key() @ ErrorCode::UnauthorizedGatewayAuthority,
+    )]
+    pub gateway_config: Account<'info, GatewayConfig>,
+    /// CHECK: PDA that owns every collection mint/metadata this program manages
+    #[account(seeds = [COLLECTION_AUTHORITY_SEED], bump)]
+    pub collection_authority: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = collection_authority,
+        mint::freeze_authority = collection_authority,
+    )]
+    pub collection_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = collection_mint,
+        associated_token::authority = collection_authority,
+    )]
+    pub collection_token_account: Account<'info, TokenAccount>,
+    /// CHECK: Metaplex metadata PDA for `collection_mint`, initialized via CPI below.
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+    /// CHECK: Metaplex master edition PDA for `collection_mint`, initialized via CPI below.
+    #[account(mut)]
+    pub collection_master_edition: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CollectionConfig::LEN,
+        seeds = [
+            CollectionConfig::SEED,
+            &source_chain.to_le_bytes(),
+            &source_collection_id,
+        ],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<InitCollection>,
+    source_chain: u16,
+    source_collection_id: [u8; 32],
+    name: String,
+    symbol: String,
+    metadata_uri: String,
+) -> Result<()> {
+    let (collection_authority_pda, collection_authority_bump) = derive_collection_authority_pda();
+    require_keys_eq!(
+        ctx.accounts.collection_authority.key(),
+        collection_authority_pda,
+        ErrorCode::InvalidPayload
+    );
+    let collection_authority_seeds: &[&[u8]] =
+        &[COLLECTION_AUTHORITY_SEED, &[collection_authority_bump]];
+
+    anchor_spl::token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::MintTo {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                to: ctx.accounts.collection_token_account.to_account_info(),
+                authority: ctx.accounts.collection_authority.to_account_info(),
+            },
+            &[collection_authority_seeds],
+        ),
+        1,
+    )?;
+
+    let (metadata_pda, _metadata_bump) = derive_metadata_pda(&ctx.accounts.collection_mint.key());
+    require_keys_eq!(
+        ctx.accounts.collection_metadata.key(),
+        metadata_pda,
+        ErrorCode::InvalidPayload
+    );
+    let (master_edition_pda, _master_edition_bump) =
+        derive_master_edition_pda(&ctx.accounts.collection_mint.key());
+    require_keys_eq!(
+        ctx.accounts.collection_master_edition.key(),
+        master_edition_pda,
+        ErrorCode::InvalidPayload
+    );
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &mpl_token_metadata::instruction::create_metadata_accounts_v3(
+            mpl_token_metadata::ID,
+            metadata_pda,
+            ctx.accounts.collection_mint.key(),
+            collection_authority_pda,
+            ctx.accounts.payer.key(),
+            collection_authority_pda,
+            name,
+            symbol,
+            metadata_uri,
+            None,
+            0,
+            true,
+            true,
+            None,
+            None,
+            None,
+        ),
+        &[
+            ctx.accounts.collection_metadata.to_account_info(),
+            ctx.accounts.collection_mint.to_account_info(),
+            ctx.accounts.collection_authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.collection_authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        &[collection_authority_seeds],
+    )?;
+
+    // max_supply = Some(0): the collection mint is itself a 1/1, the same as
+    // every NFT wrapped into it.
+    anchor_lang::solana_program::program::invoke_signed(
+        &mpl_token_metadata::instruction::create_master_edition_v3(
+            mpl_token_metadata::ID,
+            master_edition_pda,
+            ctx.accounts.collection_mint.key(),
+            collection_authority_pda,
+            collection_authority_pda,
+            metadata_pda,
+            ctx.accounts.payer.key(),
+            Some(0),
+        ),
+        &[
+            ctx.accounts.collection_master_edition.to_account_info(),
+            ctx.accounts.collection_mint.to_account_info(),
+            ctx.accounts.collection_authority.to_account_info(),
+            ctx.accounts.collection_authority.to_account_info(),
+            ctx.accounts.collection_metadata.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        &[collection_authority_seeds],
+    )?;
+
+    ctx.accounts.collection_config.set_inner(CollectionConfig {
+        source_chain,
+        source_collection_id,
+        collection_mint: ctx.accounts.collection_mint.key(),
+        bump: ctx.bumps.collection_config,
+    });
+
+    msg!("Initialized bridged NFT collection");
+    msg!("Collection Mint: {}", ctx.accounts.collection_mint.key());
+
+    Ok(())
+}
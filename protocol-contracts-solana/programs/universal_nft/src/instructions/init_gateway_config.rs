@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::gateway::{AllowedEmitter, GatewayConfig, MAX_ALLOWED_EMITTERS};
+
+#[derive(Accounts)]
+pub struct InitGatewayConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + GatewayConfig::LEN,
+        seeds = [GatewayConfig::SEED],
+        bump,
+    )]
+    pub gateway_config: Account<'info, GatewayConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitGatewayConfig>,
+    authority: Pubkey,
+    allowed_emitters: Vec<AllowedEmitter>,
+    replay_retention_seconds: i64,
+) -> Result<()> {
+    require!(
+        allowed_emitters.len() <= MAX_ALLOWED_EMITTERS,
+        ErrorCode::TooManyAllowedEmitters
+    );
+
+    ctx.accounts.gateway_config.set_inner(GatewayConfig {
+        authority,
+        nonce: 0,
+        allowed_emitters,
+        replay_retention_seconds,
+        bump: ctx.bumps.gateway_config,
+    });
+
+    msg!("Initialized gateway config");
+    msg!("Authority: {}", authority);
+
+    Ok(())
+}
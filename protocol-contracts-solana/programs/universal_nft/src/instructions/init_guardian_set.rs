@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::gateway::GatewayConfig;
+use crate::state::guardian::{GuardianSet, MAX_GUARDIANS};
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct InitGuardianSet<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + GuardianSet::LEN,
+        seeds = [GuardianSet::SEED, &index.to_le_bytes()],
+        bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+    #[account(
+        seeds = [GatewayConfig::SEED],
+        bump = gateway_config.bump,
+        constraint = gateway_config.authority == payer.key() @ ErrorCode::UnauthorizedGatewayAuthority,
+    )]
+    pub gateway_config: Account<'info, GatewayConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitGuardianSet>,
+    index: u32,
+    guardians: Vec<[u8; 20]>,
+) -> Result<()> {
+    require!(
+        guardians.len() <= MAX_GUARDIANS,
+        ErrorCode::TooManyGuardians
+    );
+
+    ctx.accounts.guardian_set.set_inner(GuardianSet {
+        index,
+        guardians,
+        bump: ctx.bumps.guardian_set,
+    });
+
+    msg!("Initialized guardian set {}", index);
+
+    Ok(())
+}
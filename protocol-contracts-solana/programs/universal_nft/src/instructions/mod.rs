@@ -0,0 +1,17 @@
+pub mod handle_incoming;
+pub mod init_collection;
+pub mod init_gateway_config;
+pub mod init_guardian_set;
+pub mod prune_replay_marker;
+pub mod prune_sequence_marker;
+pub mod transfer_out;
+pub mod update_guardian_set;
+
+pub use handle_incoming::*;
+pub use init_collection::*;
+pub use init_gateway_config::*;
+pub use init_guardian_set::*;
+pub use prune_replay_marker::*;
+pub use prune_sequence_marker::*;
+pub use transfer_out::*;
+pub use update_guardian_set::*;
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::gateway::GatewayConfig;
+use crate::state::nonce_watermark::NonceWatermark;
+use crate::state::replay::ReplayMarker;
+
+#[derive(Accounts)]
+#[instruction(token_id: [u8; 32], nonce: u64)]
+pub struct PruneReplayMarker<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        close = receiver,
+        seeds = [ReplayMarker::SEED, token_id.as_ref(), &nonce.to_le_bytes()],
+        bump,
+    )]
+    pub replay_marker: Account<'info, ReplayMarker>,
+    /// CHECK: refunded the marker's rent; must be the marker's original payer
+    #[account(mut, address = replay_marker.payer @ ErrorCode::UnauthorizedPrune)]
+    pub receiver: UncheckedAccount<'info>,
+    #[account(seeds = [GatewayConfig::SEED], bump = gateway_config.bump)]
+    pub gateway_config: Account<'info, GatewayConfig>,
+    /// CHECK: per-origin-token watermark, created here if this is its first prune
+    #[account(mut, seeds = [NonceWatermark::SEED, token_id.as_ref()], bump)]
+    pub nonce_watermark: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<PruneReplayMarker>, token_id: [u8; 32], nonce: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts.signer.key() == ctx.accounts.replay_marker.payer
+            || ctx.accounts.signer.key() == ctx.accounts.gateway_config.authority,
+        ErrorCode::UnauthorizedPrune
+    );
+    require!(
+        clock.unix_timestamp - ctx.accounts.replay_marker.created_at
+            >= ctx.accounts.gateway_config.replay_retention_seconds,
+        ErrorCode::ReplayMarkerNotYetPrunable
+    );
+
+    // Raise the per-origin-token watermark past this nonce before the marker
+    // closes, so the same (token_id, nonce) can never be replayed once its
+    // dedicated marker is gone.
+    let (nonce_watermark_pda, nonce_watermark_bump) =
+        crate::utils::derive_nonce_watermark_pda(&token_id);
+    require_keys_eq!(
+        ctx.accounts.nonce_watermark.key(),
+        nonce_watermark_pda,
+        ErrorCode::InvalidPayload
+    );
+    let next_valid_nonce = nonce.checked_add(1).ok_or(ErrorCode::NonceOverflow)?;
+
+    let min_valid_nonce = if ctx.accounts.nonce_watermark.data_is_empty() {
+        let space = 8 + NonceWatermark::LEN;
+        let lamports = Rent::get()?.minimum_balance(space);
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::create_account(
+                &ctx.accounts.signer.key(),
+                &nonce_watermark_pda,
+                lamports,
+                space as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.signer.to_account_info(),
+                ctx.accounts.nonce_watermark.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[
+                NonceWatermark::SEED,
+                token_id.as_ref(),
+                &[nonce_watermark_bump],
+            ]],
+        )?;
+        let watermark = NonceWatermark {
+            token_id,
+            min_valid_nonce: next_valid_nonce,
+            bump: nonce_watermark_bump,
+        };
+        let mut data = ctx.accounts.nonce_watermark.try_borrow_mut_data()?;
+        watermark.try_serialize(&mut &mut data[..])?;
+        next_valid_nonce
+    } else {
+        let data = ctx.accounts.nonce_watermark.try_borrow_data()?;
+        let mut watermark = NonceWatermark::try_deserialize(&mut &data[..])?;
+        drop(data);
+        if next_valid_nonce > watermark.min_valid_nonce {
+            watermark.min_valid_nonce = next_valid_nonce;
+        }
+        let mut data = ctx.accounts.nonce_watermark.try_borrow_mut_data()?;
+        watermark.try_serialize(&mut &mut data[..])?;
+        watermark.min_valid_nonce
+    };
+
+    msg!(
+        "Pruned replay marker for token {}",
+        hex::encode(&token_id[..8])
+    );
+    msg!("Nonce watermark now {}", min_valid_nonce);
+
+    Ok(())
+}
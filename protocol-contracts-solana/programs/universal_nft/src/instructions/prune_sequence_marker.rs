@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::gateway::GatewayConfig;
+use crate::state::sequence_marker::SequenceMarker;
+use crate::state::sequence_watermark::SequenceWatermark;
+use crate::utils::derive_sequence_watermark_pda;
+
+#[derive(Accounts)]
+#[instruction(emitter_chain: u16, emitter_address: [u8; 32], sequence: u64)]
+pub struct PruneSequenceMarker<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        close = receiver,
+        seeds = [
+            SequenceMarker::SEED,
+            &emitter_chain.to_le_bytes(),
+            &emitter_address,
+            &sequence.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub sequence_marker: Account<'info, SequenceMarker>,
+    /// CHECK: refunded the marker's rent; must be the marker's original payer
+    #[account(mut, address = sequence_marker.payer @ ErrorCode::UnauthorizedPrune)]
+    pub receiver: UncheckedAccount<'info>,
+    #[account(seeds = [GatewayConfig::SEED], bump = gateway_config.bump)]
+    pub gateway_config: Account<'info, GatewayConfig>,
+    /// CHECK: per-emitter watermark, created here if this is its first prune
+    #[account(
+        mut,
+        seeds = [SequenceWatermark::SEED, &emitter_chain.to_le_bytes(), &emitter_address],
+        bump,
+    )]
+    pub sequence_watermark: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<PruneSequenceMarker>,
+    emitter_chain: u16,
+    emitter_address: [u8; 32],
+    sequence: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts.signer.key() == ctx.accounts.sequence_marker.payer
+            || ctx.accounts.signer.key() == ctx.accounts.gateway_config.authority,
+        ErrorCode::UnauthorizedPrune
+    );
+    require!(
+        clock.unix_timestamp - ctx.accounts.sequence_marker.created_at
+            >= ctx.accounts.gateway_config.replay_retention_seconds,
+        ErrorCode::SequenceMarkerNotYetPrunable
+    );
+
+    // Raise the per-emitter watermark past this sequence before the marker
+    // closes, so the same sequence can never be replayed once its dedicated
+    // marker is gone.
+    let (sequence_watermark_pda, sequence_watermark_bump) =
+        derive_sequence_watermark_pda(emitter_chain, &emitter_address);
+    require_keys_eq!(
+        ctx.accounts.sequence_watermark.key(),
+        sequence_watermark_pda,
+        ErrorCode::InvalidPayload
+    );
+    let next_valid_sequence = sequence.checked_add(1).ok_or(ErrorCode::NonceOverflow)?;
+
+    let min_valid_sequence = if ctx.accounts.sequence_watermark.data_is_empty() {
+        let space = 8 + SequenceWatermark::LEN;
+        let lamports = Rent::get()?.minimum_balance(space);
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::create_account(
+                &ctx.accounts.signer.key(),
+                &sequence_watermark_pda,
+                lamports,
+                space as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.signer.to_account_info(),
+                ctx.accounts.sequence_watermark.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[
+                SequenceWatermark::SEED,
+                &emitter_chain.to_le_bytes(),
+                &emitter_address,
+                &[sequence_watermark_bump],
+            ]],
+        )?;
+        let watermark = SequenceWatermark {
+            emitter_chain,
+            emitter_address,
+            min_valid_sequence: next_valid_sequence,
+            bump: sequence_watermark_bump,
+        };
+        let mut data = ctx.accounts.sequence_watermark.try_borrow_mut_data()?;
+        watermark.try_serialize(&mut &mut data[..])?;
+        next_valid_sequence
+    } else {
+        let data = ctx.accounts.sequence_watermark.try_borrow_data()?;
+        let mut watermark = SequenceWatermark::try_deserialize(&mut &data[..])?;
+        drop(data);
+        if next_valid_sequence > watermark.min_valid_sequence {
+            watermark.min_valid_sequence = next_valid_sequence;
+        }
+        let mut data = ctx.accounts.sequence_watermark.try_borrow_mut_data()?;
+        watermark.try_serialize(&mut &mut data[..])?;
+        watermark.min_valid_sequence
+    };
+
+    msg!(
+        "Pruned sequence marker for emitter chain {} sequence {}",
+        emitter_chain,
+        sequence
+    );
+    msg!("Sequence watermark now {}", min_valid_sequence);
+
+    Ok(())
+}
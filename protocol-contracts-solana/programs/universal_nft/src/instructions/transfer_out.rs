@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::SOLANA_CHAIN_ID;
+use crate::errors::ErrorCode;
+use crate::state::custody::CustodyRecord;
+use crate::state::gateway::GatewayConfig;
+use crate::state::nft_origin::NftOrigin;
+use crate::utils::{derive_custody_record_pda, CUSTODY_AUTHORITY_SEED};
+
+#[derive(Accounts)]
+#[instruction(token_id: [u8; 32])]
+pub struct TransferOut<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = payer,
+        constraint = token_account.amount == 1 @ ErrorCode::InvalidTokenAmount,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+    /// CHECK: origin record for a wrapped NFT; left uninitialized for a natively-minted one
+    #[account(seeds = [NftOrigin::SEED, token_id.as_ref()], bump)]
+    pub nft_origin: UncheckedAccount<'info>,
+    /// CHECK: program-owned authority over custody token accounts, signer for nothing
+    #[account(seeds = [CUSTODY_AUTHORITY_SEED], bump)]
+    pub custody_authority: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = custody_authority,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+    /// CHECK: marks a natively-originated mint as locked; only written on the lock path
+    #[account(mut, seeds = [CustodyRecord::SEED, mint.key().as_ref()], bump)]
+    pub custody_record: UncheckedAccount<'info>,
+    #[account(mut, seeds = [GatewayConfig::SEED], bump = gateway_config.bump)]
+    pub gateway_config: Account<'info, GatewayConfig>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<TransferOut>,
+    token_id: [u8; 32],
+    destination_chain_id: u16,
+    destination_recipient: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    ctx.accounts.gateway_config.nonce = ctx
+        .accounts
+        .gateway_config
+        .nonce
+        .checked_add(1)
+        .ok_or(ErrorCode::NonceOverflow)?;
+    let nonce = ctx.accounts.gateway_config.nonce;
+
+    let (origin_chain, origin_token_id, origin_mint, metadata_uri) =
+        if ctx.accounts.nft_origin.data_is_empty() {
+            // No NftOrigin PDA for this mint: it was minted natively on Solana.
+            // Lock it in program custody instead of burning it.
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.token_account.to_account_info(),
+                        to: ctx.accounts.custody_token_account.to_account_info(),
+                        authority: ctx.accounts.payer.to_account_info(),
+                    },
+                ),
+                1,
+            )?;
+
+            let (custody_record_pda, custody_bump) =
+                derive_custody_record_pda(&ctx.accounts.mint.key());
+            require_keys_eq!(
+                ctx.accounts.custody_record.key(),
+                custody_record_pda,
+                ErrorCode::CustodyPdaMismatch
+            );
+            if ctx.accounts.custody_record.data_is_empty() {
+                let space = 8 + CustodyRecord::LEN;
+                let lamports = Rent::get()?.minimum_balance(space);
+                anchor_lang::solana_program::program::invoke_signed(
+                    &anchor_lang::solana_program::system_instruction::create_account(
+                        &ctx.accounts.payer.key(),
+                        &custody_record_pda,
+                        lamports,
+                        space as u64,
+                        &crate::ID,
+                    ),
+                    &[
+                        ctx.accounts.payer.to_account_info(),
+                        ctx.accounts.custody_record.to_account_info(),
+                        ctx.accounts.system_program.to_account_info(),
+                    ],
+                    &[&[
+                        CustodyRecord::SEED,
+                        ctx.accounts.mint.key().as_ref(),
+                        &[custody_bump],
+                    ]],
+                )?;
+            }
+            let record = CustodyRecord {
+                mint: ctx.accounts.mint.key(),
+                depositor: ctx.accounts.payer.key(),
+                locked_at: clock.unix_timestamp,
+                bump: custody_bump,
+            };
+            let mut data = ctx.accounts.custody_record.try_borrow_mut_data()?;
+            record.try_serialize(&mut &mut data[..])?;
+
+            (
+                SOLANA_CHAIN_ID,
+                token_id,
+                ctx.accounts.mint.key().to_bytes(),
+                String::new(),
+            )
+        } else {
+            let data = ctx.accounts.nft_origin.try_borrow_data()?;
+            let origin = NftOrigin::try_deserialize(&mut &data[..])?;
+            drop(data);
+            require_keys_eq!(
+                ctx.accounts.mint.key(),
+                origin.wrapped_mint,
+                ErrorCode::NftOriginMintMismatch
+            );
+
+            token::burn(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.token_account.to_account_info(),
+                        authority: ctx.accounts.payer.to_account_info(),
+                    },
+                ),
+                1,
+            )?;
+
+            (
+                origin.origin_chain,
+                origin.origin_token_id,
+                origin.origin_mint,
+                origin.metadata_uri,
+            )
+        };
+
+    emit!(CrossChainBurnEvent {
+        origin_chain,
+        origin_token_id,
+        origin_mint,
+        metadata_uri,
+        destination_chain_id,
+        destination_recipient,
+        nonce,
+    });
+
+    msg!("Sent Universal NFT cross-chain");
+    msg!("Destination Chain: {}", destination_chain_id);
+    msg!("Nonce: {}", nonce);
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CrossChainBurnEvent {
+    pub origin_chain: u16,
+    pub origin_token_id: [u8; 32],
+    pub origin_mint: [u8; 32],
+    pub metadata_uri: String,
+    pub destination_chain_id: u16,
+    pub destination_recipient: [u8; 32],
+    pub nonce: u64,
+}
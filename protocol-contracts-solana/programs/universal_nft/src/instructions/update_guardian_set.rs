@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::gateway::GatewayConfig;
+use crate::state::guardian::{GuardianSet, MAX_GUARDIANS};
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct UpdateGuardianSet<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GuardianSet::SEED, &index.to_le_bytes()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+    #[account(
+        seeds = [GatewayConfig::SEED],
+        bump = gateway_config.bump,
+        constraint = gateway_config.authority == payer.key() @ ErrorCode::UnauthorizedGatewayAuthority,
+    )]
+    pub gateway_config: Account<'info, GatewayConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateGuardianSet>,
+    _index: u32,
+    guardians: Vec<[u8; 20]>,
+) -> Result<()> {
+    require!(
+        guardians.len() <= MAX_GUARDIANS,
+        ErrorCode::TooManyGuardians
+    );
+
+    ctx.accounts.guardian_set.guardians = guardians;
+
+    msg!("Updated guardian set {}", ctx.accounts.guardian_set.index);
+
+    Ok(())
+}
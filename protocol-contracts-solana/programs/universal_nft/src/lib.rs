@@ -1,5 +1,14 @@
 use anchor_lang::prelude::*;
 
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod utils;
+pub mod vaa;
+
+use instructions::*;
+
 declare_id!("FXFjiHkZLqR9TWdGRcYAZPvFZLSXNrfKD3rwPTPoB8Xe");
 
 #[program]
@@ -10,6 +19,93 @@ pub mod universal_nft {
         msg!("Initialized!");
         Ok(())
     }
+
+    pub fn handle_incoming_wrap(ctx: Context<HandleIncomingWrap>, vaa: Vec<u8>) -> Result<()> {
+        instructions::handle_incoming::wrap_handler(ctx, vaa)
+    }
+
+    pub fn handle_incoming_release(ctx: Context<HandleIncomingRelease>, vaa: Vec<u8>) -> Result<()> {
+        instructions::handle_incoming::release_handler(ctx, vaa)
+    }
+
+    pub fn transfer_out(
+        ctx: Context<TransferOut>,
+        token_id: [u8; 32],
+        destination_chain_id: u16,
+        destination_recipient: [u8; 32],
+    ) -> Result<()> {
+        instructions::transfer_out::handler(
+            ctx,
+            token_id,
+            destination_chain_id,
+            destination_recipient,
+        )
+    }
+
+    pub fn init_collection(
+        ctx: Context<InitCollection>,
+        source_chain: u16,
+        source_collection_id: [u8; 32],
+        name: String,
+        symbol: String,
+        metadata_uri: String,
+    ) -> Result<()> {
+        instructions::init_collection::handler(
+            ctx,
+            source_chain,
+            source_collection_id,
+            name,
+            symbol,
+            metadata_uri,
+        )
+    }
+
+    pub fn prune_replay_marker(
+        ctx: Context<PruneReplayMarker>,
+        token_id: [u8; 32],
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::prune_replay_marker::handler(ctx, token_id, nonce)
+    }
+
+    pub fn prune_sequence_marker(
+        ctx: Context<PruneSequenceMarker>,
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+        sequence: u64,
+    ) -> Result<()> {
+        instructions::prune_sequence_marker::handler(ctx, emitter_chain, emitter_address, sequence)
+    }
+
+    pub fn init_gateway_config(
+        ctx: Context<InitGatewayConfig>,
+        authority: Pubkey,
+        allowed_emitters: Vec<crate::state::gateway::AllowedEmitter>,
+        replay_retention_seconds: i64,
+    ) -> Result<()> {
+        instructions::init_gateway_config::handler(
+            ctx,
+            authority,
+            allowed_emitters,
+            replay_retention_seconds,
+        )
+    }
+
+    pub fn init_guardian_set(
+        ctx: Context<InitGuardianSet>,
+        index: u32,
+        guardians: Vec<[u8; 20]>,
+    ) -> Result<()> {
+        instructions::init_guardian_set::handler(ctx, index, guardians)
+    }
+
+    pub fn update_guardian_set(
+        ctx: Context<UpdateGuardianSet>,
+        index: u32,
+        guardians: Vec<[u8; 20]>,
+    ) -> Result<()> {
+        instructions::update_guardian_set::handler(ctx, index, guardians)
+    }
 }
 
 #[derive(Accounts)]
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Maps a source-chain collection identifier to the Solana collection mint
+/// this program created for it, so multiple source collections can each get
+/// their own verified Solana collection.
+#[account]
+pub struct CollectionConfig {
+    pub source_chain: u16,
+    pub source_collection_id: [u8; 32],
+    pub collection_mint: Pubkey,
+    pub bump: u8,
+}
+
+impl CollectionConfig {
+    pub const SEED: &'static [u8] = b"collection_config";
+    pub const LEN: usize = 2 + 32 + 32 + 1;
+}
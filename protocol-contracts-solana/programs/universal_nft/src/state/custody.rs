@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Marks a natively-minted NFT as locked in program custody after an
+/// outbound transfer, so it can be released on a later inbound message.
+#[account]
+pub struct CustodyRecord {
+    pub mint: Pubkey,
+    pub depositor: Pubkey,
+    pub locked_at: i64,
+    pub bump: u8,
+}
+
+impl CustodyRecord {
+    pub const SEED: &'static [u8] = b"custody_record";
+    pub const LEN: usize = 32 + 32 + 8 + 1;
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of source-chain emitters this gateway will accept VAAs from.
+pub const MAX_ALLOWED_EMITTERS: usize = 8;
+
+/// A source-chain emitter permitted to originate inbound transfers.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AllowedEmitter {
+    pub chain_id: u16,
+    pub emitter_address: [u8; 32],
+}
+
+#[account]
+pub struct GatewayConfig {
+    pub authority: Pubkey,
+    /// Monotonically increasing nonce for outbound transfers from this chain.
+    pub nonce: u64,
+    /// Emitters inbound VAAs must originate from.
+    pub allowed_emitters: Vec<AllowedEmitter>,
+    /// Seconds a `ReplayMarker` must sit untouched before `PruneReplayMarker`
+    /// will close it and refund its rent.
+    pub replay_retention_seconds: i64,
+    pub bump: u8,
+}
+
+impl GatewayConfig {
+    pub const SEED: &'static [u8] = b"gateway_config";
+    pub const LEN: usize = 32 + 8 + (4 + (2 + 32) * MAX_ALLOWED_EMITTERS) + 8 + 1;
+}
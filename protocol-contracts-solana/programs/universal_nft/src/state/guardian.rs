@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of guardians tracked in a single set, matching Wormhole's
+/// Solana guardian set cap.
+pub const MAX_GUARDIANS: usize = 19;
+
+/// An ordered set of guardian addresses authorized to sign VAAs, identified
+/// by `index` so multiple sets can coexist across a guardian set rotation.
+#[account]
+pub struct GuardianSet {
+    pub index: u32,
+    pub guardians: Vec<[u8; 20]>,
+    pub bump: u8,
+}
+
+impl GuardianSet {
+    pub const SEED: &'static [u8] = b"guardian_set";
+    pub const LEN: usize = 4 + (4 + 20 * MAX_GUARDIANS) + 1;
+}
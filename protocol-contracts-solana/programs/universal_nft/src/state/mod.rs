@@ -0,0 +1,9 @@
+pub mod collection;
+pub mod custody;
+pub mod gateway;
+pub mod guardian;
+pub mod nft_origin;
+pub mod nonce_watermark;
+pub mod replay;
+pub mod sequence_marker;
+pub mod sequence_watermark;
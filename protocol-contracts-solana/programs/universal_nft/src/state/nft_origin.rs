@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct NftOrigin {
+    pub origin_chain: u16,
+    pub origin_token_id: [u8; 32],
+    pub origin_mint: [u8; 32],
+    pub metadata_uri: String,
+    /// The Solana mint this origin record was wrapped into, so an outbound
+    /// transfer can look up the origin record for a given mint.
+    pub wrapped_mint: Pubkey,
+    /// Identifier of the collection this NFT belongs to on its origin chain,
+    /// so multiple source collections map to distinct Solana collection mints.
+    pub source_collection_id: [u8; 32],
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl NftOrigin {
+    pub const SEED: &'static [u8] = b"nft_origin";
+    // 2 (origin_chain) + 32 (origin_token_id) + 32 (origin_mint) + 4 + 200 (metadata_uri)
+    // + 32 (wrapped_mint) + 32 (source_collection_id) + 8 (created_at) + 1 (bump)
+    pub const LEN: usize = 2 + 32 + 32 + (4 + 200) + 32 + 32 + 8 + 1;
+}
+
+/// Upper bound on `CrossChainNftPayload::app_payload`, enforced in
+/// `verify_and_consume_vaa` so a malicious payload can't blow out the
+/// transaction's compute/size budget.
+pub const MAX_APP_PAYLOAD_LEN: usize = 1024;
+
+/// Cross-chain payload carried by an inbound transfer message. Routing fields
+/// mirror the origin-chain NFT so `handle_incoming_wrap` can mint a faithful
+/// wrapped copy (or `handle_incoming_release` can release a custodied one).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CrossChainNftPayload {
+    pub token_id: [u8; 32],
+    pub origin_chain_id: u16,
+    pub origin_mint: [u8; 32],
+    pub name: String,
+    pub symbol: String,
+    pub metadata_uri: String,
+    pub recipient: Pubkey,
+    pub nonce: u64,
+    /// The account that initiated this transfer on the origin chain.
+    pub sender: [u8; 32],
+    /// Opaque bytes a composing app can attach to the transfer, e.g. to drive
+    /// a contract-controlled transfer or relayer pattern on the destination.
+    pub app_payload: Option<Vec<u8>>,
+    /// Identifier of the collection this NFT belongs to on the origin chain.
+    pub source_collection_id: [u8; 32],
+}
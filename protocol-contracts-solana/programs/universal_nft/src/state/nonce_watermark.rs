@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Per-origin-token high-water mark for accepted nonces. Once a `ReplayMarker`
+/// is pruned, replay protection for its `(token_id, nonce)` pair can no
+/// longer rely on the marker's absence, so every nonce at or below it is
+/// rejected by `verify_and_consume_vaa` in `handle_incoming` instead.
+#[account]
+pub struct NonceWatermark {
+    pub token_id: [u8; 32],
+    pub min_valid_nonce: u64,
+    pub bump: u8,
+}
+
+impl NonceWatermark {
+    pub const SEED: &'static [u8] = b"nonce_watermark";
+    pub const LEN: usize = 32 + 8 + 1;
+}
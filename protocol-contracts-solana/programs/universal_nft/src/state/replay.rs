@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ReplayMarker {
+    pub token_id: [u8; 32],
+    pub nonce: u64,
+    pub created_at: i64,
+    /// Original fee payer, refunded the account's rent when it is pruned.
+    pub payer: Pubkey,
+    pub bump: u8,
+}
+
+impl ReplayMarker {
+    pub const SEED: &'static [u8] = b"replay_marker";
+    pub const LEN: usize = 32 + 8 + 8 + 32 + 1;
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Dedup marker keyed on a guardian-signed VAA's (emitter_chain,
+/// emitter_address, sequence). Kept independent of `ReplayMarker`, which dedups
+/// on the bridge's own (token_id, nonce): a VAA sequence must never be
+/// replayed regardless of the application payload it carries.
+#[account]
+pub struct SequenceMarker {
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub created_at: i64,
+    /// Original fee payer, refunded the account's rent when it is pruned.
+    pub payer: Pubkey,
+    pub bump: u8,
+}
+
+impl SequenceMarker {
+    pub const SEED: &'static [u8] = b"sequence_marker";
+    pub const LEN: usize = 2 + 32 + 8 + 8 + 32 + 1;
+}
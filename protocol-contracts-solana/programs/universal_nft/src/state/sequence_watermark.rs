@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Per-emitter high-water mark for accepted VAA sequences. Once a
+/// `SequenceMarker` is pruned, replay protection for its sequence can no
+/// longer rely on the marker's absence, so every sequence at or below it is
+/// rejected by `handle_incoming` instead.
+#[account]
+pub struct SequenceWatermark {
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub min_valid_sequence: u64,
+    pub bump: u8,
+}
+
+impl SequenceWatermark {
+    pub const SEED: &'static [u8] = b"sequence_watermark";
+    pub const LEN: usize = 2 + 32 + 8 + 1;
+}
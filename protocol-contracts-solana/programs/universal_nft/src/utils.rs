@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::state::collection::CollectionConfig;
+use crate::state::custody::CustodyRecord;
+use crate::state::guardian::GuardianSet;
+use crate::state::nft_origin::NftOrigin;
+use crate::state::nonce_watermark::NonceWatermark;
+use crate::state::replay::ReplayMarker;
+use crate::state::sequence_marker::SequenceMarker;
+use crate::state::sequence_watermark::SequenceWatermark;
+
+/// Seed for the PDA that holds mint/freeze authority over every wrapped NFT
+/// this program mints, and that signs the accompanying metadata CPIs.
+pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
+
+/// Seed for the PDA that owns every custody token account holding a
+/// natively-minted NFT locked for an outbound transfer.
+pub const CUSTODY_AUTHORITY_SEED: &[u8] = b"custody_authority";
+
+/// Seed for the PDA that is update authority over every collection this
+/// program manages, and that signs `verify_sized_collection_item` CPIs.
+pub const COLLECTION_AUTHORITY_SEED: &[u8] = b"collection_authority";
+
+pub fn derive_nft_origin_pda(token_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NftOrigin::SEED, token_id], &crate::ID)
+}
+
+pub fn derive_replay_marker_pda(token_id: &[u8; 32], nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ReplayMarker::SEED, token_id, &nonce.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+pub fn derive_mint_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MINT_AUTHORITY_SEED], &crate::ID)
+}
+
+pub fn derive_metadata_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"metadata", mpl_token_metadata::ID.as_ref(), mint.as_ref()],
+        &mpl_token_metadata::ID,
+    )
+}
+
+pub fn derive_master_edition_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            mpl_token_metadata::ID.as_ref(),
+            mint.as_ref(),
+            b"edition",
+        ],
+        &mpl_token_metadata::ID,
+    )
+}
+
+pub fn derive_custody_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CUSTODY_AUTHORITY_SEED], &crate::ID)
+}
+
+pub fn derive_custody_record_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CustodyRecord::SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn derive_guardian_set_pda(index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GuardianSet::SEED, &index.to_le_bytes()], &crate::ID)
+}
+
+pub fn derive_sequence_marker_pda(
+    emitter_chain: u16,
+    emitter_address: &[u8; 32],
+    sequence: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SequenceMarker::SEED,
+            &emitter_chain.to_le_bytes(),
+            emitter_address,
+            &sequence.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn derive_sequence_watermark_pda(emitter_chain: u16, emitter_address: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SequenceWatermark::SEED,
+            &emitter_chain.to_le_bytes(),
+            emitter_address,
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn derive_collection_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLECTION_AUTHORITY_SEED], &crate::ID)
+}
+
+pub fn derive_nonce_watermark_pda(token_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NonceWatermark::SEED, token_id], &crate::ID)
+}
+
+pub fn derive_collection_config_pda(
+    source_chain: u16,
+    source_collection_id: &[u8; 32],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            CollectionConfig::SEED,
+            &source_chain.to_le_bytes(),
+            source_collection_id,
+        ],
+        &crate::ID,
+    )
+}
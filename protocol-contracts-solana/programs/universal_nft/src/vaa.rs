@@ -0,0 +1,94 @@
+use std::collections::BTreeSet;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
+
+use crate::errors::ErrorCode;
+use crate::state::guardian::GuardianSet;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GuardianSignature {
+    pub guardian_index: u8,
+    pub signature: [u8; 65],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VaaHeader {
+    pub version: u8,
+    pub guardian_set_index: u32,
+    pub signatures: Vec<GuardianSignature>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VaaBody {
+    pub timestamp: i64,
+    pub nonce: u64,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub consistency_level: u8,
+    pub payload: Vec<u8>,
+}
+
+/// A guardian-signed message: `header` carries the signatures over `body`,
+/// computed from the exact bytes in `body_bytes`.
+pub struct Vaa {
+    pub header: VaaHeader,
+    pub body: VaaBody,
+    pub body_bytes: Vec<u8>,
+}
+
+impl Vaa {
+    /// Splits `data` into a borsh-encoded header followed by the raw body
+    /// bytes the guardians signed, and parses both.
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let mut cursor = data;
+        let header = VaaHeader::deserialize(&mut cursor).map_err(|_| ErrorCode::InvalidVaa)?;
+        let body_bytes = cursor.to_vec();
+        let body = VaaBody::try_from_slice(&body_bytes).map_err(|_| ErrorCode::InvalidVaa)?;
+        Ok(Self {
+            header,
+            body,
+            body_bytes,
+        })
+    }
+
+    /// `keccak256(keccak256(body_bytes))`, the digest guardians sign.
+    fn body_hash(&self) -> [u8; 32] {
+        keccak::hash(&keccak::hash(&self.body_bytes).to_bytes()).to_bytes()
+    }
+
+    /// Recovers each signer via the `secp256k1_recover` syscall and checks it against
+    /// `guardian_set`, requiring at least `floor(2/3 * N) + 1` distinct valid
+    /// signatures from guardians in that set.
+    pub fn verify_signatures(&self, guardian_set: &GuardianSet) -> Result<()> {
+        require!(
+            self.header.guardian_set_index == guardian_set.index,
+            ErrorCode::GuardianSetMismatch
+        );
+
+        let hash = self.body_hash();
+        let mut valid_guardians = BTreeSet::new();
+
+        for sig in &self.header.signatures {
+            let expected = guardian_set
+                .guardians
+                .get(sig.guardian_index as usize)
+                .ok_or(ErrorCode::InvalidGuardianIndex)?;
+
+            let recovery_id = sig.signature[64];
+            let recovered = secp256k1_recover(&hash, recovery_id, &sig.signature[..64])
+                .map_err(|_| ErrorCode::InvalidGuardianSignature)?;
+            let address = &keccak::hash(&recovered.to_bytes()).to_bytes()[12..32];
+
+            if address == expected {
+                valid_guardians.insert(sig.guardian_index);
+            }
+        }
+
+        let quorum = guardian_set.guardians.len() * 2 / 3 + 1;
+        require!(valid_guardians.len() >= quorum, ErrorCode::QuorumNotMet);
+        Ok(())
+    }
+}